@@ -0,0 +1,17 @@
+use crate::db::MySqlConPool;
+use std::collections::HashSet;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+
+// カテゴリ名の存在確認を都度DBに問い合わせずに済ませるためのインメモリキャッシュ
+pub type CategoryCache = Arc<RwLock<HashSet<String>>>;
+
+// 起動時にカテゴリ一覧を読み込み、インメモリキャッシュを構築する
+pub async fn load_category_cache(db: &MySqlConPool) -> CategoryCache {
+    let names = sqlx::query_scalar!("select name from categories")
+        .fetch_all(db.as_ref())
+        .await
+        .unwrap_or_default();
+
+    Arc::new(RwLock::new(names.into_iter().collect()))
+}
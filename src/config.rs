@@ -0,0 +1,76 @@
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use clap::Parser;
+use serde::Deserialize;
+
+// config.tomlから読み込む設定値
+#[derive(Deserialize)]
+struct FileConfig {
+    addr: Option<SocketAddr>,
+    database: Option<String>,
+    cors_origin: Option<String>,
+}
+
+// コマンドラインから渡されるオプション（config.tomlの値を上書きする）
+#[derive(Parser)]
+#[command(author, version, about)]
+struct Cli {
+    /// 設定ファイルのパス
+    #[arg(long, default_value = "config.toml")]
+    config: PathBuf,
+
+    /// バインドするアドレス（例: 127.0.0.1:3000）
+    #[arg(long)]
+    addr: Option<SocketAddr>,
+
+    /// データベース接続文字列
+    #[arg(long)]
+    database: Option<String>,
+
+    /// CORSで許可するオリジン（未指定時は任意のオリジンを許可）
+    #[arg(long)]
+    cors_origin: Option<String>,
+}
+
+// サーバ起動時に確定する設定
+pub struct Config {
+    pub addr: SocketAddr,
+    pub database: String,
+    pub cors_origin: Option<String>,
+}
+
+impl Config {
+    // config.tomlを読み込み、コマンドライン引数で上書きして確定させる
+    pub fn load() -> Self {
+        let cli = Cli::parse();
+
+        let file_config = std::fs::read_to_string(&cli.config)
+            .ok()
+            .and_then(|content| toml::from_str::<FileConfig>(&content).ok())
+            .unwrap_or(FileConfig {
+                addr: None,
+                database: None,
+                cors_origin: None,
+            });
+
+        let addr = cli
+            .addr
+            .or(file_config.addr)
+            .unwrap_or_else(|| SocketAddr::from(([127, 0, 0, 1], 3000)));
+
+        let database = cli
+            .database
+            .or(file_config.database)
+            .or_else(|| std::env::var("DATABASE_URL").ok())
+            .expect("database url must be set via --database, config.toml, or DATABASE_URL");
+
+        let cors_origin = cli.cors_origin.or(file_config.cors_origin);
+
+        Config {
+            addr,
+            database,
+            cors_origin,
+        }
+    }
+}
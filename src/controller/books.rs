@@ -0,0 +1,293 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use sqlx::{MySql, QueryBuilder};
+
+use axum::{
+    extract::{Path, Query},
+    http::StatusCode,
+    response::IntoResponse,
+    Extension, Json,
+};
+
+use crate::db::MySqlConPool;
+use crate::error::AppError;
+
+//書籍を表す構造体
+#[derive(Serialize, sqlx::FromRow)]
+pub struct Book {
+    id: i64,
+    title: String,
+    author: String,
+    publisher: String,
+    isbn: String,
+    comment: String,
+    category_id: Option<i64>,
+    borrower: Option<String>,
+    borrowed_at: Option<NaiveDateTime>,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+}
+
+//JSONから値を取り出して保持する構造体
+#[derive(Deserialize)]
+pub struct CreateNewBook {
+    title: String,
+    author: String,
+    publisher: String,
+    isbn: String,
+    comment: String,
+    category_id: Option<i64>,
+}
+// JSONから値を取り出して保持する構造体
+#[derive(Deserialize)]
+pub struct UpdateComment {
+    comment: String,
+}
+
+// book_listのクエリパラメータ（?limit=&offset=&author=&title=&isbn=）
+#[derive(Deserialize)]
+pub struct BookFilter {
+    limit: Option<i64>,
+    offset: Option<i64>,
+    author: Option<String>,
+    title: Option<String>,
+    isbn: Option<String>,
+}
+
+// 書籍のリストとページネーション情報を表す構造体
+#[derive(Serialize)]
+pub struct BookList {
+    books: Vec<Book>,
+    total: i64,
+    limit: i64,
+    offset: i64,
+}
+
+// 貸出申請の構造体
+#[derive(Deserialize)]
+pub struct BorrowRequest {
+    borrower: String,
+}
+
+const DEFAULT_LIMIT: i64 = 50;
+
+// フィルタ条件をWHERE句として組み立てる（検索用クエリと件数クエリの両方で使い回す）
+fn push_book_filter<'a>(builder: &mut QueryBuilder<'a, MySql>, filter: &'a BookFilter) {
+    let mut has_condition = false;
+    let push_clause = |builder: &mut QueryBuilder<'a, MySql>, has_condition: &mut bool| {
+        builder.push(if *has_condition { " and " } else { " where " });
+        *has_condition = true;
+    };
+
+    if let Some(title) = &filter.title {
+        push_clause(builder, &mut has_condition);
+        builder.push("title like ").push_bind(format!("%{}%", title));
+    }
+    if let Some(author) = &filter.author {
+        push_clause(builder, &mut has_condition);
+        builder
+            .push("author like ")
+            .push_bind(format!("%{}%", author));
+    }
+    if let Some(isbn) = &filter.isbn {
+        push_clause(builder, &mut has_condition);
+        builder.push("isbn = ").push_bind(isbn);
+    }
+}
+
+pub async fn book_list(
+    Query(filter): Query<BookFilter>,
+    Extension(db): Extension<MySqlConPool>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    let limit = filter.limit.unwrap_or(DEFAULT_LIMIT);
+    let offset = filter.offset.unwrap_or(0);
+
+    let mut select_builder = QueryBuilder::<MySql>::new("select * from books");
+    push_book_filter(&mut select_builder, &filter);
+    select_builder
+        .push(" order by id")
+        .push(" limit ")
+        .push_bind(limit)
+        .push(" offset ")
+        .push_bind(offset);
+    let books = select_builder
+        .build_query_as::<Book>()
+        .fetch_all(&mut conn)
+        .await?;
+
+    let mut count_builder = QueryBuilder::<MySql>::new("select count(*) from books");
+    push_book_filter(&mut count_builder, &filter);
+    let total: i64 = count_builder
+        .build_query_as::<(i64,)>()
+        .fetch_one(&mut conn)
+        .await?
+        .0;
+
+    Ok(Json(BookList {
+        books,
+        total,
+        limit,
+        offset,
+    }))
+}
+
+pub async fn get_book(
+    Path(id): Path<i64>,
+    Extension(db): Extension<MySqlConPool>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    let book = sqlx::query_as!(Book, "select * from books where id = ?", id)
+        .fetch_one(&mut conn)
+        .await?;
+
+    Ok(Json(book))
+}
+
+pub async fn create_item(
+    Extension(db): Extension<MySqlConPool>,
+    Json(req): Json<CreateNewBook>,
+) -> Result<impl IntoResponse, AppError> {
+    //コネクションプールからコネクションを取得
+    let mut conn = db.acquire().await?;
+
+    // INSERT文実行
+    let rows_affected = sqlx::query!(
+        r#"
+        insert into books (title, author, publisher, isbn, comment, category_id, created_at, updated_at) values (?, ?, ?, ?, ?, ?, now(), now())
+        "#,
+        req.title,
+        req.author,
+        req.publisher,
+        req.isbn,
+        req.comment,
+        req.category_id,
+    )
+    //データベース書き込み処理のためにexecuteを実行
+        .execute(&mut conn)
+        .await?
+    //クエリが影響した行の数を返すように変形
+        .rows_affected();
+
+    //影響があった行が一行であれば成功、そうでなければ失敗
+    if rows_affected == 1 {
+        Ok(StatusCode::CREATED)
+    } else {
+        Err(AppError::bad_request())
+    }
+}
+
+// 書籍ID（id)と渡されるJSON（req）とデータベースへのコネクションプールを保持
+pub async fn update_comment(
+    Path(id): Path<i64>,
+    Extension(db): Extension<MySqlConPool>,
+    Json(req): Json<UpdateComment>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    //UPDATE文を発行
+    let rows_affected = sqlx::query!(
+        r#"update books set comment = ?, updated_at = now() where id = ?"#,
+        req.comment,
+        id
+    )
+        .execute(&mut conn)
+        .await?
+        .rows_affected();
+
+    if rows_affected == 1 {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found())
+    }
+}
+
+pub async fn delete_item(
+    Path(id): Path<i64>,
+    Extension(db): Extension<MySqlConPool>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    let rows_affected = sqlx::query!("delete from books where id = ?", id)
+        .execute(&mut conn)
+        .await?
+        .rows_affected();
+
+    if rows_affected == 1 {
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found())
+    }
+}
+
+// 書籍を貸し出し状態にし、loansテーブルに履歴を記録する
+pub async fn borrow_book(
+    Path(id): Path<i64>,
+    Extension(db): Extension<MySqlConPool>,
+    Json(req): Json<BorrowRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    // 存在しないidは404、貸出中のidは409と区別するため先に現在の貸出状態を見る
+    let borrower = sqlx::query_scalar!("select borrower from books where id = ?", id)
+        .fetch_one(&mut conn)
+        .await?;
+
+    if borrower.is_some() {
+        return Err(AppError::conflict());
+    }
+
+    let rows_affected = sqlx::query!(
+        "update books set borrower = ?, borrowed_at = now() where id = ? and borrower is null",
+        req.borrower,
+        id
+    )
+    .execute(&mut conn)
+    .await?
+    .rows_affected();
+
+    if rows_affected != 1 {
+        return Err(AppError::conflict());
+    }
+
+    sqlx::query!(
+        "insert into loans (book_id, borrower, borrowed_at) values (?, ?, now())",
+        id,
+        req.borrower,
+    )
+    .execute(&mut conn)
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// 書籍を返却済みにし、該当するloansの履歴を締める
+pub async fn return_book(
+    Path(id): Path<i64>,
+    Extension(db): Extension<MySqlConPool>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    let rows_affected = sqlx::query!(
+        "update books set borrower = null, borrowed_at = null where id = ? and borrower is not null",
+        id
+    )
+    .execute(&mut conn)
+    .await?
+    .rows_affected();
+
+    if rows_affected != 1 {
+        return Err(AppError::not_found());
+    }
+
+    sqlx::query!(
+        "update loans set returned_at = now() where book_id = ? and returned_at is null",
+        id
+    )
+    .execute(&mut conn)
+    .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
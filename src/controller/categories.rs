@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+
+use axum::{extract::Path, http::StatusCode, response::IntoResponse, Extension, Json};
+
+use crate::cache::CategoryCache;
+use crate::db::MySqlConPool;
+use crate::error::AppError;
+
+// カテゴリを表す構造体
+#[derive(Serialize)]
+pub struct Category {
+    id: i64,
+    name: String,
+}
+
+// カテゴリ作成リクエストの構造体
+#[derive(Deserialize)]
+pub struct CreateCategory {
+    name: String,
+}
+
+// カテゴリのリストの情報を表す構造体
+#[derive(Serialize)]
+pub struct CategoryList(Vec<Category>);
+
+pub async fn category_list(
+    Extension(db): Extension<MySqlConPool>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    let categories = sqlx::query_as!(Category, "select * from categories")
+        .fetch_all(&mut conn)
+        .await?;
+
+    Ok(Json(CategoryList(categories)))
+}
+
+pub async fn create_category(
+    Extension(db): Extension<MySqlConPool>,
+    Extension(cache): Extension<CategoryCache>,
+    Json(req): Json<CreateCategory>,
+) -> Result<impl IntoResponse, AppError> {
+    //書き込みロックを検査からキャッシュ更新まで保持し、同名カテゴリの同時作成を防ぐ
+    let mut cache = cache.write().await;
+    if cache.contains(&req.name) {
+        return Err(AppError::conflict());
+    }
+
+    let mut conn = db.acquire().await?;
+
+    //万一キャッシュと実DBがずれていても、一意制約違反はAppErrorが409へ変換する
+    sqlx::query!("insert into categories (name) values (?)", req.name)
+        .execute(&mut conn)
+        .await?;
+
+    cache.insert(req.name);
+
+    Ok(StatusCode::CREATED)
+}
+
+pub async fn delete_category(
+    Path(name): Path<String>,
+    Extension(db): Extension<MySqlConPool>,
+    Extension(cache): Extension<CategoryCache>,
+) -> Result<impl IntoResponse, AppError> {
+    let mut conn = db.acquire().await?;
+
+    let rows_affected = sqlx::query!("delete from categories where name = ?", name)
+        .execute(&mut conn)
+        .await?
+        .rows_affected();
+
+    if rows_affected == 1 {
+        cache.write().await.remove(&name);
+        Ok(StatusCode::NO_CONTENT)
+    } else {
+        Err(AppError::not_found())
+    }
+}
@@ -0,0 +1,6 @@
+use axum::{http::StatusCode, response::IntoResponse};
+
+// リクエストが送られてくるとHTTPステータスコード”204 No Content"を返すだけのエンドポイント
+pub async fn health_check() -> impl IntoResponse {
+    StatusCode::NO_CONTENT
+}
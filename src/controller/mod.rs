@@ -0,0 +1,3 @@
+pub mod books;
+pub mod categories;
+pub mod health;
@@ -0,0 +1,11 @@
+use sqlx::{MySql, MySqlPool, Pool};
+use std::sync::Arc;
+
+pub type MySqlConPool = Arc<Pool<MySql>>;
+
+// 与えられた接続文字列でコネクションプールを用意する
+pub async fn conn(database_url: &str) -> Pool<MySql> {
+    MySqlPool::connect(database_url)
+        .await
+        .expect("failed to connect to database")
+}
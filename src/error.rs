@@ -0,0 +1,52 @@
+use axum::{http::StatusCode, response::IntoResponse};
+use sqlx::error::DatabaseError;
+use sqlx::mysql::MySqlDatabaseError;
+
+// MySQLのエラー番号1062は一意制約違反（重複キー）
+// DatabaseError::code()はSQLSTATEを返すため、エラー番号はnumber()で見る
+const MYSQL_ER_DUP_ENTRY: u16 = 1062;
+
+fn is_duplicate_entry(err: &dyn DatabaseError) -> bool {
+    err.try_downcast_ref::<MySqlDatabaseError>()
+        .map_or(false, |e| e.number() == MYSQL_ER_DUP_ENTRY)
+}
+
+// ハンドラ内で発生しうるエラーをまとめて表現する型
+pub struct AppError(StatusCode);
+
+impl AppError {
+    pub fn not_found() -> Self {
+        AppError(StatusCode::NOT_FOUND)
+    }
+
+    pub fn bad_request() -> Self {
+        AppError(StatusCode::BAD_REQUEST)
+    }
+
+    pub fn conflict() -> Self {
+        AppError(StatusCode::CONFLICT)
+    }
+}
+
+impl IntoResponse for AppError {
+    fn into_response(self) -> axum::response::Response {
+        self.0.into_response()
+    }
+}
+
+// sqlxのエラーをHTTPステータスコードへ変換する
+// RowNotFoundは404、一意制約違反は409、それ以外は500として扱う
+impl From<sqlx::Error> for AppError {
+    fn from(err: sqlx::Error) -> Self {
+        match err {
+            sqlx::Error::RowNotFound => AppError(StatusCode::NOT_FOUND),
+            sqlx::Error::Database(db_err) if is_duplicate_entry(&*db_err) => {
+                AppError(StatusCode::CONFLICT)
+            }
+            err => {
+                tracing::error!("{:?}", err);
+                AppError(StatusCode::INTERNAL_SERVER_ERROR)
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use axum::{Extension, Router};
+
+pub mod cache;
+pub mod config;
+pub mod controller;
+pub mod db;
+pub mod error;
+pub mod router;
+
+use cache::load_category_cache;
+use db::MySqlConPool;
+
+// コネクションプールからアプリケーション全体のRouterを組み立てる
+// main()だけでなく、ソケットを開かないテストからも呼び出せる
+pub async fn app(pool: MySqlConPool) -> Router {
+    let category_cache = load_category_cache(&pool).await;
+
+    router::health()
+        .nest("/books", router::books())
+        .nest("/categories", router::categories())
+        .layer(Extension(pool))
+        .layer(Extension(category_cache))
+}
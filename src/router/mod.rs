@@ -0,0 +1,31 @@
+use axum::{
+    routing::{delete, get, patch, post},
+    Router,
+};
+
+use crate::controller::{books, categories, health};
+
+// /healthエンドポイントを提供するルータ
+pub fn health() -> Router {
+    Router::new().route("/health", get(health::health_check))
+}
+
+// /books以下のエンドポイントを提供するルータ
+pub fn books() -> Router {
+    Router::new()
+        .route("/", get(books::book_list))
+        .route("/", post(books::create_item))
+        .route("/:id", get(books::get_book))
+        .route("/:id", patch(books::update_comment))
+        .route("/:id", delete(books::delete_item))
+        .route("/:id/borrow", post(books::borrow_book))
+        .route("/:id/return", post(books::return_book))
+}
+
+// /categories以下のエンドポイントを提供するルータ
+pub fn categories() -> Router {
+    Router::new()
+        .route("/", get(categories::category_list))
+        .route("/", post(categories::create_category))
+        .route("/:name", delete(categories::delete_category))
+}